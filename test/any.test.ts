@@ -0,0 +1,30 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.any / z.unknown", () => {
+  test("z.any", () => {
+    expect(z.any).toBeDefined()
+    const schema = z.any()
+    const obj = { a: 1 }
+    expect(schema.parse(obj)).toBe(obj)
+    expect(schema.parse(undefined)).toBeUndefined()
+    expect(schema.parse(null)).toBeNull()
+    expect(schema.parse(NaN)).toBeNaN()
+    expect(schema.safeParse("a").success).toBe(true)
+    expect(z.any()._def.typeName).toBe("ZodAny")
+  })
+
+  test("z.unknown", () => {
+    expect(z.unknown).toBeDefined()
+    const schema = z.unknown()
+    expect(schema.parse(1)).toBe(1)
+    expect(schema.parse(undefined)).toBeUndefined()
+    expect(schema.safeParse(Symbol("a")).success).toBe(true)
+    expect(z.unknown()._def.typeName).toBe("ZodUnknown")
+
+    // オブジェクトのプロパティとして使用すると、どのような値も許可する
+    expect(z.object({ data: z.unknown() }).parse({ data: [1, "a"] })).toEqual({ data: [1, "a"] })
+  })
+})
@@ -0,0 +1,104 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.array", () => {
+  test("z.array", () => {
+    expect(z.array).toBeDefined()
+    expect(() => { z.array(z.string()) }).not.toThrow()
+    const schema = z.array(z.string())
+    expect(schema).toBeDefined()
+
+    expect(() => { schema.parse([]) }).not.toThrow()
+    expect(() => { schema.parse(["a", "b"]) }).not.toThrow()
+    expect(schema.parse(["a", "b"])).toEqual(["a", "b"])
+
+    // 要素の検証に失敗した場合はエラー
+    expect(() => { schema.parse(["a", 1]) }).toThrow()
+
+    // 配列以外はエラーになるべき
+    expect(() => { schema.parse("a") }).toThrow()
+    expect(() => { schema.parse({ 0: "a" }) }).toThrow() // オブジェクトはエラー
+    expect(() => { schema.parse(null) }).toThrow()
+    expect(() => { schema.parse(undefined) }).toThrow()
+
+    expect(schema.safeParse(["a"]).success).toBe(true)
+    expect(schema.safeParse([1]).success).toBe(false)
+  })
+
+  test("z.string().array", () => {
+    expect(z.string().array).toBeDefined()
+    const schema = z.string().array()
+    expect(schema._def.typeName).toBe("ZodArray")
+    expect(() => { schema.parse(["a"]) }).not.toThrow()
+    expect(() => { schema.parse([1]) }).toThrow()
+
+    // どの型からでも配列スキーマを作成できる
+    expect(() => { z.number().array().parse([1, 2]) }).not.toThrow()
+    expect(() => { z.object({ id: z.number() }).array().parse([{ id: 1 }]) }).not.toThrow()
+    expect(() => { z.string().array().array().parse([["a"], []]) }).not.toThrow()
+  })
+
+  test("z.array().element", () => {
+    const schema = z.array(z.string().min(2))
+    expect(schema.element._def.typeName).toBe("ZodString")
+    expect(() => { schema.element.parse("ab") }).not.toThrow()
+    expect(() => { schema.element.parse("a") }).toThrow()
+  })
+
+  test("z.array().nonempty", () => {
+    const schema = z.array(z.string()).nonempty()
+    expect(() => { schema.parse(["a"]) }).not.toThrow()
+    expect(() => { schema.parse([]) }).toThrow()
+    expect(z.array(z.string()).nonempty("empty").safeParse([]).error.issues[0].message).toBe("empty")
+    expect(z.array(z.string()).nonempty({ message: "empty" }).safeParse([]).error.issues[0].message).toBe("empty")
+  })
+
+  test("z.array().min / max / length", () => {
+    const minSchema = z.array(z.number()).min(2)
+    expect(() => { minSchema.parse([1, 2]) }).not.toThrow() // 境界値は許可
+    expect(() => { minSchema.parse([1]) }).toThrow()
+    expect(minSchema.safeParse([1]).error.issues[0].message).toBe("Array must contain at least 2 element(s)")
+
+    const maxSchema = z.array(z.number()).max(2)
+    expect(() => { maxSchema.parse([1, 2]) }).not.toThrow() // 境界値は許可
+    expect(() => { maxSchema.parse([1, 2, 3]) }).toThrow()
+    expect(maxSchema.safeParse([1, 2, 3]).error.issues[0].message).toBe("Array must contain at most 2 element(s)")
+
+    const lengthSchema = z.array(z.number()).length(2)
+    expect(() => { lengthSchema.parse([1, 2]) }).not.toThrow()
+    expect(() => { lengthSchema.parse([1]) }).toThrow()
+    expect(() => { lengthSchema.parse([1, 2, 3]) }).toThrow()
+    expect(lengthSchema.safeParse([1]).error.issues[0].message).toBe("Array must contain exactly 2 element(s)")
+
+    // zodと同じく、負の値や小数もそのまま数値として比較する
+    expect(() => { z.array(z.number()).min(-1).parse([]) }).not.toThrow()
+    expect(() => { z.array(z.number()).min(1.5).parse([1]) }).toThrow()
+    expect(z.array(z.number()).min(1.5).safeParse([1]).error.issues[0].minimum).toBe(1.5)
+    expect(() => { z.array(z.number()).max(1.5).parse([1, 2]) }).toThrow()
+    expect(() => { z.array(z.number()).max(-1).parse([]) }).toThrow()
+    expect(() => { z.array(z.number()).length(1.5).parse([1]) }).toThrow()
+    expect(z.array(z.number()).min(1.5)._def.minLength).toEqual({ value: 1.5 })
+  })
+
+  test("エラーのpath", () => {
+    const schema = z.array(z.string())
+    expect(schema.safeParse(["a", "b", 3]).error.issues[0].path).toEqual([2])
+
+    // ネストした配列とオブジェクトのpath
+    const nested = z.object({ items: z.array(z.object({ name: z.string() })) })
+    const result = nested.safeParse({ items: [{ name: "a" }, { name: 1 }] })
+    expect(result.success).toBe(false)
+    expect(result.error.issues[0].path).toEqual(["items", 1, "name"])
+
+    // トップレベルのエラーのpathは空
+    expect(schema.safeParse("a").error.issues[0].path).toEqual([])
+  })
+
+  test("deepPartial", () => {
+    const schema = z.object({ items: z.array(z.object({ name: z.string() })).min(1) }).deepPartial()
+    expect(() => { schema.parse({ items: [{}] }) }).not.toThrow()
+    expect(() => { schema.parse({ items: [] }) }).toThrow() // 要素数の制約は維持される
+  })
+})
@@ -193,4 +193,53 @@ describe("test z.bigint", () => {
     expect(schema.safeParse(0n).success).toBe(false)
     expect(schema.safeParse(10n).success).toBe(false)
   })
+
+  test("カスタムエラーメッセージ", () => {
+    // 文字列または { message } でメッセージを指定できる
+    expect(z.bigint().gt(5n, "gt").safeParse(5n).error.issues[0].message).toBe("gt")
+    expect(z.bigint().max(5n, { message: "max" }).safeParse(6n).error.issues[0].message).toBe("max")
+    expect(z.bigint().negative("negative").safeParse(0n).error.issues[0].message).toBe("negative")
+    expect(z.bigint().multipleOf(2n, "multipleOf").safeParse(3n).error.issues[0].message).toBe("multipleOf")
+
+    // メッセージを指定しない場合はデフォルトのメッセージ
+    expect(z.bigint().lt(5n).safeParse(5n).error.issues[0].message).toBe("BigInt must be less than 5")
+  })
+
+  test("複数の制約を順番に検証する", () => {
+    // 同じ種類の制約を重ねても上書きされず、追加された順にすべて検証される
+    const schema = z.bigint().positive().min(10n, "min10")
+    const issues = schema.safeParse(-1n).error.issues
+    expect(issues.map((issue) => issue.minimum)).toEqual([0n, 10n])
+    expect(issues[1].message).toBe("min10")
+    expect(schema.safeParse(5n).error.issues.length).toBe(1)
+
+    // _def.checksの値やminValue/maxValueはBigIntで返す
+    expect(schema._def.checks).toEqual([
+      { kind: "min", value: 0n, inclusive: false },
+      { kind: "min", value: 10n, inclusive: true, message: "min10" },
+    ])
+    expect(schema.minValue).toBe(10n)
+    expect(z.bigint().lt(5n).max(10n).maxValue).toBe(5n)
+    expect(z.bigint().minValue).toBeNull()
+  })
+
+  test("i128の範囲を超える値", () => {
+    // 256ビットの値でもpanicせず、任意精度で比較する
+    const max256 = 2n ** 256n - 1n
+    const schema = z.bigint().positive().lte(max256)
+    expect(schema.safeParse(max256).success).toBe(true)
+    expect(schema.safeParse(max256 + 1n).error.issues[0]).toMatchObject({ code: "too_big", maximum: max256 })
+    expect(schema.safeParse(-(2n ** 200n)).error.issues[0].code).toBe("too_small")
+
+    // 制約の値も任意の大きさを指定できる
+    expect(z.bigint().gt(2n ** 128n).safeParse(2n ** 128n).success).toBe(false)
+    expect(z.bigint().gt(2n ** 128n).safeParse(2n ** 128n + 1n).success).toBe(true)
+    expect(z.bigint().gt(2n ** 128n).minValue).toBe(2n ** 128n)
+
+    // 倍数の判定
+    expect(z.bigint().multipleOf(10n ** 30n).safeParse(10n ** 60n).success).toBe(true)
+    expect(z.bigint().multipleOf(10n ** 30n).safeParse(10n ** 60n + 1n).error.issues[0].multipleOf).toBe(10n ** 30n)
+    // zodと同じく、0の倍数の制約はRangeErrorを投げる
+    expect(() => z.bigint().multipleOf(0n).safeParse(2n ** 300n)).toThrow(RangeError)
+  })
 })
@@ -0,0 +1,87 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.coerce", () => {
+  test("z.coerce.string", () => {
+    const schema = z.coerce.string()
+    expect(schema.parse("a")).toBe("a")
+    expect(schema.parse(12)).toBe("12")
+    expect(schema.parse(true)).toBe("true")
+    expect(schema.parse(undefined)).toBe("undefined")
+    expect(schema.parse(null)).toBe("null")
+    expect(schema.parse(10n)).toBe("10")
+    expect(schema.parse([1, 2])).toBe("1,2")
+
+    // 変換後の値に対して制約をチェックする
+    expect(z.coerce.string().min(3).safeParse(12).success).toBe(false)
+    expect(z.coerce.string().min(3).safeParse(123).success).toBe(true)
+  })
+
+  test("z.coerce.number", () => {
+    const schema = z.coerce.number()
+    expect(schema.parse("12")).toBe(12)
+    expect(schema.parse("")).toBe(0)
+    expect(schema.parse(true)).toBe(1)
+    expect(schema.parse(null)).toBe(0)
+    expect(schema.parse(10n)).toBe(10)
+    expect(schema.parse(new Date(5))).toBe(5)
+
+    // NaNになる値はエラー
+    expect(schema.safeParse("abc").error.issues[0]).toMatchObject({ code: "invalid_type", expected: "number", received: "nan" })
+    expect(schema.safeParse(undefined).success).toBe(false)
+
+    expect(z.coerce.number().int().gte(1).safeParse("1.5").success).toBe(false)
+    expect(z.coerce.number().int().gte(1).parse("3")).toBe(3)
+  })
+
+  test("z.coerce.boolean", () => {
+    const schema = z.coerce.boolean()
+    expect(schema.parse("tuna")).toBe(true)
+    expect(schema.parse("false")).toBe(true) // 空でない文字列はtrue
+    expect(schema.parse(1)).toBe(true)
+    expect(schema.parse([])).toBe(true)
+    expect(schema.parse("")).toBe(false)
+    expect(schema.parse(0)).toBe(false)
+    expect(schema.parse(undefined)).toBe(false)
+    expect(schema.parse(null)).toBe(false)
+  })
+
+  test("z.coerce.bigint", () => {
+    const schema = z.coerce.bigint()
+    expect(schema.parse("12")).toBe(12n)
+    expect(schema.parse(12)).toBe(12n)
+    expect(schema.parse(true)).toBe(1n)
+    expect(schema.parse("")).toBe(0n)
+
+    // BigInt()で変換できない値は元の値の型でinvalid_type
+    expect(schema.safeParse("abc").error.issues[0]).toMatchObject({ code: "invalid_type", expected: "bigint", received: "string" })
+    expect(schema.safeParse(1.5).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "bigint", received: "number" })
+    expect(schema.safeParse(undefined).error.issues[0].message).toBe("Required")
+
+    expect(z.coerce.bigint().positive().safeParse("-1").success).toBe(false)
+  })
+
+  test("z.coerce.date", () => {
+    const schema = z.coerce.date()
+    expect(schema.parse("2024-01-01T00:00:00Z")).toEqual(new Date("2024-01-01T00:00:00Z"))
+    expect(schema.parse(0)).toEqual(new Date(0))
+    expect(schema.parse(new Date(5))).toEqual(new Date(5))
+
+    // Invalid Dateになる値はinvalid_date
+    expect(schema.safeParse("abc").error.issues[0].code).toBe("invalid_date")
+    expect(z.coerce.date().min(new Date(1000)).safeParse(0).error.issues[0].code).toBe("too_small")
+  })
+
+  test("オプションと_def.coerce", () => {
+    // 通常のスキーマと同じくオプションを指定できる
+    expect(z.coerce.number({ invalid_type_error: "not a number" }).safeParse("abc").error.issues[0].message).toBe("not a number")
+
+    expect(z.coerce.string()._def.coerce).toBe(true)
+    expect(z.coerce.string().min(1)._def.coerce).toBe(true)
+    expect(z.string()._def.coerce).toBe(false)
+    expect(z.number({ coerce: true }).parse("1")).toBe(1)
+    expect(z.coerce.date()._def.typeName).toBe("ZodDate")
+  })
+})
@@ -0,0 +1,78 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.date", () => {
+  test("z.date", () => {
+    expect(z.date).toBeDefined()
+    expect(() => { z.date() }).not.toThrow()
+    const schema = z.date()
+    expect(() => { schema.parse(new Date()) }).not.toThrow()
+    expect(() => { schema.parse(new Date(0)) }).not.toThrow()
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse("2024-01-01") }).toThrow() // 文字列はエラー
+    expect(() => { schema.parse(1704067200000) }).toThrow() // 数値はエラー
+    expect(() => { schema.parse({}) }).toThrow() // オブジェクトはエラー
+    expect(() => { schema.parse(null) }).toThrow() // nullはエラー
+
+    // Dateは "date" 型として扱う
+    expect(schema.safeParse({}).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "date", received: "object" })
+    expect(z.string().safeParse(new Date()).error.issues[0].received).toBe("date")
+  })
+
+  test("パース結果はコピーされたDate", () => {
+    const input = new Date(1704067200000)
+    const output = z.date().parse(input)
+    expect(output instanceof Date).toBe(true)
+    expect(output.getTime()).toBe(input.getTime())
+    expect(output).not.toBe(input)
+  })
+
+  test("Invalid Dateはinvalid_date", () => {
+    const result = z.date().safeParse(new Date("invalid"))
+    expect(result.success).toBe(false)
+    expect(result.error.issues).toEqual([{ code: "invalid_date", path: [], message: "Invalid date" }])
+  })
+
+  test("z.date().min / max", () => {
+    const min = new Date("2024-01-01T00:00:00Z")
+    const max = new Date("2024-12-31T00:00:00Z")
+    const schema = z.date().min(min).max(max)
+    expect(schema.safeParse(min).success).toBe(true) // 境界値は許可
+    expect(schema.safeParse(max).success).toBe(true)
+    expect(schema.safeParse(new Date("2024-06-01T00:00:00Z")).success).toBe(true)
+
+    const small = schema.safeParse(new Date("2023-12-31T23:59:59Z")).error.issues[0]
+    expect(small).toMatchObject({ code: "too_small", minimum: min.getTime(), type: "date", inclusive: true, exact: false })
+    expect(small.message).toBe(`Date must be greater than or equal to ${min}`)
+
+    const big = schema.safeParse(new Date("2025-01-01T00:00:00Z")).error.issues[0]
+    expect(big).toMatchObject({ code: "too_big", maximum: max.getTime(), type: "date" })
+    expect(big.message).toBe(`Date must be smaller than or equal to ${max}`)
+  })
+
+  test("カスタムエラーメッセージ", () => {
+    const min = new Date("2024-01-01T00:00:00Z")
+    expect(z.date().min(min, "too early").safeParse(new Date(0)).error.issues[0].message).toBe("too early")
+    expect(z.date().max(min, { message: "too late" }).safeParse(new Date()).error.issues[0].message).toBe("too late")
+    expect(z.date({ invalid_type_error: "not a date" }).safeParse("a").error.issues[0].message).toBe("not a date")
+  })
+
+  test("minDate / maxDate", () => {
+    expect(z.date().minDate).toBeNull()
+    expect(z.date().maxDate).toBeNull()
+
+    // 複数ある場合は最も厳しい値を返す
+    const schema = z.date().min(new Date(1000)).min(new Date(2000)).max(new Date(5000)).max(new Date(4000))
+    expect(schema.minDate).toEqual(new Date(2000))
+    expect(schema.maxDate).toEqual(new Date(4000))
+    expect(schema._def.checks).toEqual([
+      { kind: "min", value: 1000 },
+      { kind: "min", value: 2000 },
+      { kind: "max", value: 5000 },
+      { kind: "max", value: 4000 },
+    ])
+  })
+})
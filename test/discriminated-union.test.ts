@@ -0,0 +1,114 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.discriminatedUnion", () => {
+  const schema = z.discriminatedUnion("status", [
+    z.object({ status: z.literal("success"), data: z.string() }),
+    z.object({ status: z.literal("failed"), error: z.number() }),
+  ])
+
+  test("z.discriminatedUnion", () => {
+    expect(z.discriminatedUnion).toBeDefined()
+    expect(schema.parse({ status: "success", data: "a" })).toEqual({ status: "success", data: "a" })
+    expect(schema.parse({ status: "failed", error: 1 })).toEqual({ status: "failed", error: 1 })
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse({ status: "success", error: 1 }) }).toThrow()
+    expect(() => { schema.parse({ status: "unknown" }) }).toThrow()
+    expect(() => { schema.parse({}) }).toThrow()
+    expect(() => { schema.parse("success") }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodDiscriminatedUnion")
+    expect(schema._def.discriminator).toBe("status")
+    expect(schema.discriminator).toBe("status")
+  })
+
+  test("options / optionsMap", () => {
+    expect(schema.options.length).toBe(2)
+    expect(schema._def.options.length).toBe(2)
+
+    const optionsMap = schema.optionsMap
+    expect(optionsMap instanceof Map).toBe(true)
+    expect(Array.from(optionsMap.keys())).toEqual(["success", "failed"])
+    expect(optionsMap.get("failed")).toBe(schema.options[1])
+    expect(schema._def.optionsMap).toBe(optionsMap)
+  })
+
+  test("判別キーの値に対応する選択肢のissueのみを返す", () => {
+    const issues = schema.safeParse({ status: "failed", error: "a" }).error.issues
+    expect(issues).toEqual([
+      { code: "invalid_type", expected: "number", received: "string", path: ["error"], message: "Expected number, received string" },
+    ])
+  })
+
+  test("invalid_union_discriminator", () => {
+    expect(schema.safeParse({ status: "unknown" }).error.issues).toEqual([
+      {
+        code: "invalid_union_discriminator",
+        options: ["success", "failed"],
+        path: ["status"],
+        message: "Invalid discriminator value. Expected 'success' | 'failed'",
+      },
+    ])
+
+    // ネストした位置のpathが保持される
+    const nested = z.object({ result: schema }).safeParse({ result: {} }).error.issues[0]
+    expect(nested.path).toEqual(["result", "status"])
+
+    // スキーマ固有のerrorMapも使用される
+    const custom = z.discriminatedUnion("type", [z.object({ type: z.literal("a") })], { errorMap: (issue) => ({ message: issue.code }) })
+    expect(custom.safeParse({ type: "b" }).error.issues[0].message).toBe("invalid_union_discriminator")
+  })
+
+  test("オブジェクト以外はinvalid_type", () => {
+    expect(schema.safeParse(null).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "object", received: "null", path: [] })
+    expect(schema.safeParse([]).error.issues[0].received).toBe("array")
+  })
+
+  test("判別キーのスキーマの種類", () => {
+    const Fruits = { Apple: "apple", Banana: "banana" }
+    const union = z.discriminatedUnion("type", [
+      z.object({ type: z.enum(["a", "b"]), value: z.string() }),
+      z.object({ type: z.nativeEnum(Fruits), value: z.number() }),
+      z.object({ type: z.literal(1), value: z.boolean() }),
+      z.object({ type: z.null(), value: z.null() }),
+      z.object({ type: z.undefined() }),
+    ])
+    expect(Array.from(union.optionsMap.keys())).toEqual(["a", "b", "apple", "banana", 1, null, undefined])
+    expect(union.parse({ type: "b", value: "x" })).toEqual({ type: "b", value: "x" })
+    expect(union.parse({ type: "banana", value: 1 })).toEqual({ type: "banana", value: 1 })
+    expect(union.parse({ type: 1, value: true })).toEqual({ type: 1, value: true })
+    expect(union.parse({ type: null, value: null })).toEqual({ type: null, value: null })
+    expect(union.parse({})).toEqual({})
+
+    const optional = z.discriminatedUnion("type", [
+      z.object({ type: z.literal("a").optional() }),
+      z.object({ type: z.literal("b").nullable() }),
+    ])
+    expect(Array.from(optional.optionsMap.keys())).toEqual([undefined, "a", null, "b"])
+
+    // zodと同じく、メッセージ中のnullとundefinedは空文字列になる（Array.prototype.joinと同じ）
+    expect(optional.safeParse({ type: "c" }).error.issues[0]).toMatchObject({
+      code: "invalid_union_discriminator",
+      options: [undefined, "a", null, "b"],
+      message: "Invalid discriminator value. Expected  | 'a' |  | 'b'",
+    })
+  })
+
+  test("作成時のエラー", () => {
+    // 判別キーの値が重複している
+    expect(() => {
+      z.discriminatedUnion("type", [z.object({ type: z.literal("a") }), z.object({ type: z.enum(["b", "a"]) })])
+    }).toThrow("Discriminator property type has duplicate value a")
+
+    // 判別キーの値を取得できない選択肢がある
+    expect(() => {
+      z.discriminatedUnion("type", [z.object({ type: z.literal("a") }), z.object({ type: z.string() })])
+    }).toThrow("A discriminator value for key `type` could not be extracted from all schema options")
+    expect(() => {
+      z.discriminatedUnion("type", [z.object({ other: z.literal("a") })])
+    }).toThrow()
+  })
+})
@@ -0,0 +1,66 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.enum", () => {
+  test("z.enum", () => {
+    expect(z.enum).toBeDefined()
+    const schema = z.enum(["Salmon", "Tuna", "Trout"])
+    expect(schema.parse("Tuna")).toBe("Tuna")
+    expect(schema._def.typeName).toBe("ZodEnum")
+    expect(schema._def.values).toEqual(["Salmon", "Tuna", "Trout"])
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse("Bass") }).toThrow()
+    expect(() => { schema.parse("tuna") }).toThrow()
+    expect(() => { schema.parse(1) }).toThrow()
+  })
+
+  test("issue", () => {
+    const schema = z.enum(["Salmon", "Tuna"])
+    expect(schema.safeParse("Bass").error.issues).toEqual([
+      {
+        code: "invalid_enum_value",
+        received: "Bass",
+        options: ["Salmon", "Tuna"],
+        path: [],
+        message: "Invalid enum value. Expected 'Salmon' | 'Tuna', received 'Bass'",
+      },
+    ])
+    // 文字列以外は許可される値を expected に持つ invalid_type
+    expect(schema.safeParse(1).error.issues[0]).toMatchObject({
+      code: "invalid_type", expected: "'Salmon' | 'Tuna'", received: "number",
+    })
+
+    // messageはinvalid_enum_valueにも使用される
+    expect(z.enum(["a"], { message: "custom" }).safeParse("b").error.issues[0].message).toBe("custom")
+    expect(z.enum(["a"], { required_error: "required" }).safeParse(undefined).error.issues[0].message).toBe("required")
+  })
+
+  test("options / enum / Values / Enum", () => {
+    const schema = z.enum(["Salmon", "Tuna"])
+    expect(schema.options).toEqual(["Salmon", "Tuna"])
+    expect(schema.enum).toEqual({ Salmon: "Salmon", Tuna: "Tuna" })
+    expect(schema.Values).toEqual({ Salmon: "Salmon", Tuna: "Tuna" })
+    expect(schema.Enum).toEqual({ Salmon: "Salmon", Tuna: "Tuna" })
+    expect(schema.enum.Tuna).toBe("Tuna")
+  })
+
+  test("extract / exclude", () => {
+    const schema = z.enum(["Salmon", "Tuna", "Trout"])
+
+    const salmonAndTrout = schema.extract(["Salmon", "Trout"])
+    expect(salmonAndTrout.options).toEqual(["Salmon", "Trout"])
+    expect(salmonAndTrout.safeParse("Tuna").success).toBe(false)
+
+    const noTrout = schema.exclude(["Trout"])
+    expect(noTrout.options).toEqual(["Salmon", "Tuna"])
+    expect(noTrout.safeParse("Trout").error.issues[0].options).toEqual(["Salmon", "Tuna"])
+
+    // 元のスキーマのオプションを引き継ぎ、指定した場合は上書きする
+    const custom = z.enum(["a", "b"], { message: "custom" })
+    expect(custom.exclude(["b"]).safeParse("b").error.issues[0].message).toBe("custom")
+    expect(custom.extract(["a"], { message: "extracted" }).safeParse("b").error.issues[0].message).toBe("extracted")
+  })
+})
@@ -0,0 +1,93 @@
+import { create_zod } from "@zod-rs/wasm"
+import { afterEach, describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test errorMap", () => {
+  afterEach(() => {
+    // グローバルなerrorMapを元に戻す
+    z.setErrorMap(z.defaultErrorMap)
+  })
+
+  test("z.setErrorMap / z.getErrorMap", () => {
+    expect(z.getErrorMap()).toBe(z.defaultErrorMap)
+
+    const errorMap = (issue, ctx) => {
+      if (issue.code === "invalid_type") {
+        return { message: `型が正しくありません: ${issue.expected}` }
+      }
+      return { message: ctx.defaultError }
+    }
+    z.setErrorMap(errorMap)
+    expect(z.getErrorMap()).toBe(errorMap)
+
+    expect(z.string().safeParse(1).error.issues[0].message).toBe("型が正しくありません: string")
+    // defaultErrorにはデフォルトのメッセージが渡される
+    expect(z.string().min(2).safeParse("a").error.issues[0].message).toBe("String must contain at least 2 character(s)")
+
+    // デフォルトに戻す
+    z.setErrorMap(z.defaultErrorMap)
+    expect(z.getErrorMap()).toBe(z.defaultErrorMap)
+    expect(z.string().safeParse(1).error.issues[0].message).toBe("Expected string, received number")
+  })
+
+  test("z.defaultErrorMap", () => {
+    const issue = { code: "too_small", minimum: 3, type: "string", inclusive: true, exact: false, path: [] }
+    expect(z.defaultErrorMap(issue, { defaultError: "", data: "a" })).toEqual({ message: "String must contain at least 3 character(s)" })
+    expect(z.defaultErrorMap({ code: "invalid_type", expected: "string", received: "undefined", path: [] }, { defaultError: "", data: undefined }).message).toBe("Required")
+  })
+
+  test("スキーマ固有のerrorMap", () => {
+    const schema = z.string({ errorMap: (issue, ctx) => ({ message: `${issue.code}: ${ctx.data}` }) })
+    expect(schema.safeParse(1).error.issues[0].message).toBe("invalid_type: 1")
+    expect(schema._def.errorMap).toBeDefined()
+
+    // 制約のメッセージが指定されている場合はそちらが優先される
+    expect(schema.min(3, "short").safeParse("a").error.issues[0].message).toBe("short")
+    expect(schema.min(3).safeParse("a").error.issues[0].message).toBe("too_small: a")
+
+    // 子スキーマのissueには適用されない
+    const object = z.object({ name: z.string() }, { errorMap: () => ({ message: "object" }) })
+    expect(object.safeParse(1).error.issues[0].message).toBe("object")
+    expect(object.safeParse({ name: 1 }).error.issues[0].message).toBe("Expected string, received number")
+  })
+
+  test("invalid_type_error / required_error / message", () => {
+    const schema = z.string({ invalid_type_error: "文字列ではありません", required_error: "必須です" })
+    expect(schema.safeParse(1).error.issues[0].message).toBe("文字列ではありません")
+    expect(schema.safeParse(undefined).error.issues[0].message).toBe("必須です")
+    // 型以外のissueには影響しない
+    expect(schema.min(2).safeParse("a").error.issues[0].message).toBe("String must contain at least 2 character(s)")
+
+    expect(z.number({ message: "数値が必要です" }).safeParse("a").error.issues[0].message).toBe("数値が必要です")
+    expect(z.object({ a: z.number({ required_error: "aは必須です" }) }).safeParse({}).error.issues[0].message).toBe("aは必須です")
+
+    // errorMapと同時には指定できない
+    expect(() => { z.string({ errorMap: () => ({ message: "" }), invalid_type_error: "" }) }).toThrow(
+      "Can't use \"invalid_type_error\" or \"required_error\" in conjunction with custom error map."
+    )
+  })
+
+  test("parseの呼び出し時のerrorMap", () => {
+    const errorMap = (issue, ctx) => ({ message: `contextual: ${ctx.defaultError}` })
+    const schema = z.object({ name: z.string({ invalid_type_error: "schema" }), age: z.number() })
+
+    const issues = schema.safeParse({ name: 1, age: "a" }, { errorMap }).error.issues
+    // スキーマ固有のerrorMapの結果がdefaultErrorとして渡される
+    expect(issues[0].message).toBe("contextual: schema")
+    expect(issues[1].message).toBe("contextual: Expected number, received string")
+
+    expect(() => { z.string().parse(1, { errorMap }) }).toThrow("contextual: Expected string, received number")
+  })
+
+  test("優先順位", () => {
+    z.setErrorMap(() => ({ message: "global" }))
+    expect(z.string().safeParse(1).error.issues[0].message).toBe("global")
+    expect(z.string({ errorMap: () => ({ message: "schema" }) }).safeParse(1).error.issues[0].message).toBe("schema")
+    expect(
+      z.string({ errorMap: () => ({ message: "schema" }) }).safeParse(1, { errorMap: () => ({ message: "contextual" }) }).error.issues[0].message
+    ).toBe("contextual")
+    // 制約のメッセージが最優先
+    expect(z.string().min(2, "check").safeParse("a", { errorMap: () => ({ message: "contextual" }) }).error.issues[0].message).toBe("check")
+  })
+})
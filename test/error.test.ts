@@ -0,0 +1,154 @@
+import { create_zod, ZodError } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test ZodError", () => {
+  test("invalid_type", () => {
+    const result = z.string().safeParse(1)
+    expect(result.success).toBe(false)
+    expect(result.error.issues).toEqual([
+      { code: "invalid_type", expected: "string", received: "number", path: [], message: "Expected string, received number" },
+    ])
+
+    // undefinedの場合のメッセージは "Required"
+    expect(z.number().safeParse(undefined).error.issues[0].message).toBe("Required")
+    expect(z.number().safeParse(NaN).error.issues[0].received).toBe("nan")
+  })
+
+  test("too_small / too_big", () => {
+    const small = z.string().min(3).safeParse("a").error.issues[0]
+    expect(small.code).toBe("too_small")
+    expect(small.minimum).toBe(3)
+    expect(small.type).toBe("string")
+    expect(small.inclusive).toBe(true)
+    expect(small.exact).toBe(false)
+    expect(small.message).toBe("String must contain at least 3 character(s)")
+
+    const big = z.number().lt(5).safeParse(5).error.issues[0]
+    expect(big.code).toBe("too_big")
+    expect(big.maximum).toBe(5)
+    expect(big.inclusive).toBe(false)
+    expect(big.message).toBe("Number must be less than 5")
+
+    const bigint = z.bigint().gte(10n).safeParse(1n).error.issues[0]
+    expect(bigint.code).toBe("too_small")
+    expect(bigint.minimum).toBe(10n)
+    expect(bigint.type).toBe("bigint")
+  })
+
+  test("その他のissueコード", () => {
+    expect(z.string().email().safeParse("a").error.issues[0]).toMatchObject({ code: "invalid_string", validation: "email", message: "Invalid email" })
+    expect(z.string().startsWith("a").safeParse("b").error.issues[0].validation).toEqual({ startsWith: "a" })
+    expect(z.number().int().safeParse(1.5).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "integer", received: "float" })
+    expect(z.number().multipleOf(3).safeParse(4).error.issues[0]).toMatchObject({ code: "not_multiple_of", multipleOf: 3 })
+    expect(z.number().finite().safeParse(Infinity).error.issues[0].code).toBe("not_finite")
+    expect(z.object({}).strict().safeParse({ a: 1 }).error.issues[0]).toMatchObject({ code: "unrecognized_keys", keys: ["a"] })
+  })
+
+  test("すべてのissueを収集する", () => {
+    // 1つの値に対する複数の制約違反
+    const codes = z.string().min(5).email().safeParse("a").error.issues.map((issue) => issue.code)
+    expect(codes).toEqual(["too_small", "invalid_string"])
+
+    // オブジェクトの複数のプロパティのエラー
+    const schema = z.object({ name: z.string(), age: z.number().positive() })
+    const issues = schema.safeParse({ name: 1, age: -1 }).error.issues
+    expect(issues.length).toBe(2)
+    expect(issues[0].path).toEqual(["name"])
+    expect(issues[1].path).toEqual(["age"])
+    expect(issues[1].code).toBe("too_small")
+  })
+
+  test("parseはissueを持つエラーを投げる", () => {
+    let error
+    try {
+      z.object({ name: z.string() }).parse({})
+    } catch (e) {
+      error = e
+    }
+    expect(error).toBeDefined()
+    expect(error.issues[0]).toMatchObject({ code: "invalid_type", path: ["name"], message: "Required" })
+    expect(JSON.parse(error.message)).toEqual(error.issues)
+  })
+
+  test("ZodErrorクラス", () => {
+    // parseはZodErrorのインスタンスを投げる
+    expect(() => { z.string().parse(1) }).toThrow(ZodError)
+    expect(() => { z.string().parse(1) }).toThrow(Error)
+
+    let error
+    try {
+      z.string().parse(1)
+    } catch (e) {
+      error = e
+    }
+    expect(error instanceof ZodError).toBe(true)
+    expect(error instanceof Error).toBe(true)
+    expect(error.name).toBe("ZodError")
+    expect(typeof error.stack).toBe("string")
+    expect(error.errors).toEqual(error.issues)
+
+    // safeParseのエラーも同じクラス
+    const result = z.string().safeParse(1)
+    expect(result.error instanceof ZodError).toBe(true)
+
+    // z.ZodErrorからも参照できる
+    expect(z.ZodError).toBe(ZodError)
+    expect(result.error instanceof z.ZodError).toBe(true)
+  })
+
+  test("flatten / formErrors", () => {
+    const schema = z.object({ name: z.string(), tags: z.array(z.string()) }).strict()
+    const error = schema.safeParse({ name: 1, tags: ["a", 2, 3], extra: true }).error
+
+    expect(error.flatten()).toEqual({
+      formErrors: ["Unrecognized key(s) in object: 'extra'"],
+      fieldErrors: {
+        name: ["Expected string, received number"],
+        tags: ["Expected string, received number", "Expected string, received number"],
+      },
+    })
+    expect(error.formErrors).toEqual(error.flatten())
+
+    // mapperを指定するとその戻り値が使われる
+    expect(error.flatten((issue) => issue.code).fieldErrors.name).toEqual(["invalid_type"])
+
+    // mapperが投げたエラーはそのまま伝播する
+    expect(() => error.flatten(() => { throw new Error("mapper error") })).toThrow("mapper error")
+  })
+
+  test("format", () => {
+    const schema = z.object({ user: z.object({ name: z.string() }), tags: z.array(z.string()) })
+    const error = schema.safeParse({ user: { name: 1 }, tags: ["a", 2] }).error
+
+    expect(error.format()).toEqual({
+      _errors: [],
+      user: { _errors: [], name: { _errors: ["Expected string, received number"] } },
+      tags: { _errors: [], 1: { _errors: ["Expected string, received number"] } },
+    })
+    expect(error.format((issue) => issue.code).user.name._errors).toEqual(["invalid_type"])
+    expect(() => error.format(() => { throw new Error("mapper error") })).toThrow("mapper error")
+
+    // pathが空のissueはトップレベルの_errorsに追加される
+    expect(z.string().safeParse(1).error.format()).toEqual({ _errors: ["Expected string, received number"] })
+  })
+
+  test("isEmpty / addIssue / addIssues / toString", () => {
+    const error = new ZodError([])
+    expect(error.isEmpty).toBe(true)
+
+    const issue = { code: "custom", path: ["a"], message: "custom error" }
+    error.addIssue(issue)
+    expect(error.isEmpty).toBe(false)
+    expect(error.issues).toEqual([issue])
+
+    error.addIssues([issue, issue])
+    expect(error.issues.length).toBe(3)
+    error.addIssues()
+    expect(error.issues.length).toBe(3)
+
+    expect(error.toString()).toBe(error.message)
+    expect(error.toString()).toBe(JSON.stringify(error.issues, null, 2))
+  })
+})
@@ -0,0 +1,79 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.intersection", () => {
+  const Person = z.object({ name: z.string() })
+  const Employee = z.object({ role: z.string() })
+
+  test("z.intersection", () => {
+    expect(z.intersection).toBeDefined()
+    const schema = z.intersection(Person, Employee)
+    // 両方のスキーマの結果がマージされる
+    expect(schema.parse({ name: "Alice", role: "admin" })).toEqual({ name: "Alice", role: "admin" })
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse({ name: "Alice" }) }).toThrow()
+    expect(() => { schema.parse({ role: "admin" }) }).toThrow()
+    expect(() => { schema.parse(null) }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodIntersection")
+    expect(schema._def.left).toBe(Person)
+    expect(schema._def.right).toBe(Employee)
+  })
+
+  test(".and", () => {
+    const schema = Person.and(Employee)
+    expect(schema._def.typeName).toBe("ZodIntersection")
+    expect(schema.parse({ name: "Alice", role: "admin", extra: 1 })).toEqual({ name: "Alice", role: "admin" })
+
+    // unionとの組み合わせ
+    const numbers = z.union([z.number(), z.string()]).and(z.union([z.number(), z.boolean()]))
+    expect(numbers.parse(1)).toBe(1)
+    expect(() => { numbers.parse("a") }).toThrow()
+  })
+
+  test("両側のissueを収集する", () => {
+    const issues = z.intersection(Person, Employee).safeParse({}).error.issues
+    expect(issues.map((issue) => issue.path)).toEqual([["name"], ["role"]])
+
+    // dirtyな結果のissueも返す
+    const dirty = z.string().min(3).and(z.string().max(1)).safeParse("ab").error.issues
+    expect(dirty.map((issue) => issue.code)).toEqual(["too_small", "too_big"])
+  })
+
+  test("mergeValues", () => {
+    // ネストしたオブジェクトはキーごとにマージされる
+    const nested = z.object({ user: z.object({ id: z.number() }) })
+      .and(z.object({ user: z.object({ name: z.string() }) }))
+    expect(nested.parse({ user: { id: 1, name: "Alice" } })).toEqual({ user: { id: 1, name: "Alice" } })
+
+    // 配列は要素ごとにマージされる
+    const array = z.array(z.object({ a: z.number() })).and(z.array(z.object({ b: z.number() })))
+    expect(array.parse([{ a: 1, b: 2 }, { a: 3, b: 4 }])).toEqual([{ a: 1, b: 2 }, { a: 3, b: 4 }])
+
+    // 同じ日時のDateはマージできる
+    const date = new Date(0)
+    expect(z.date().and(z.date()).parse(date).getTime()).toBe(0)
+  })
+
+  test("invalid_intersection_types", () => {
+    // 両側で異なる値になる場合はマージできない
+    const schema = z.object({ a: z.string() }).passthrough().and(z.object({ a: z.coerce.string() }))
+    expect(schema.safeParse({ a: "1" }).success).toBe(true)
+
+    const mismatched = z.coerce.number().and(z.coerce.string())
+    expect(mismatched.safeParse(1).error.issues).toEqual([
+      { code: "invalid_intersection_types", path: [], message: "Intersection results could not be merged" },
+    ])
+
+    // 配列の要素が異なる値になる場合
+    const array = z.array(z.coerce.number()).and(z.array(z.coerce.string()))
+    expect(array.safeParse([1]).error.issues[0].code).toBe("invalid_intersection_types")
+
+    // ネストした位置のpathが保持される
+    const nested = z.object({ value: mismatched }).safeParse({ value: 1 }).error.issues[0]
+    expect(nested).toMatchObject({ code: "invalid_intersection_types", path: ["value"] })
+  })
+})
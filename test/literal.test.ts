@@ -0,0 +1,57 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.literal", () => {
+  test("z.literal", () => {
+    expect(z.literal).toBeDefined()
+    const schema = z.literal("v2")
+    expect(schema.parse("v2")).toBe("v2")
+    expect(schema.value).toBe("v2")
+    expect(schema._def.value).toBe("v2")
+    expect(schema._def.typeName).toBe("ZodLiteral")
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse("v1") }).toThrow()
+    expect(() => { schema.parse("V2") }).toThrow()
+    expect(() => { schema.parse(2) }).toThrow()
+    expect(() => { schema.parse(undefined) }).toThrow()
+  })
+
+  test("invalid_literal", () => {
+    expect(z.literal("v2").safeParse("v1").error.issues).toEqual([
+      { code: "invalid_literal", expected: "v2", received: "v1", path: [], message: "Invalid literal value, expected \"v2\"" },
+    ])
+    expect(z.literal(5n).safeParse(6n).error.issues[0].message).toBe("Invalid literal value, expected \"5\"")
+    expect(z.literal(undefined).safeParse(1).error.issues[0].message).toBe("Invalid literal value, expected undefined")
+    expect(z.literal("a", { errorMap: (issue) => ({ message: `${issue.code}: ${String(issue.received)}` }) }).safeParse("b").error.issues[0].message)
+      .toBe("invalid_literal: b")
+  })
+
+  test("各種の値", () => {
+    expect(z.literal(42).safeParse(42).success).toBe(true)
+    expect(z.literal(42).safeParse("42").success).toBe(false) // 型が違う値は一致しない
+    expect(z.literal(true).safeParse(true).success).toBe(true)
+    expect(z.literal(true).safeParse(1).success).toBe(false)
+    expect(z.literal(null).safeParse(null).success).toBe(true)
+    expect(z.literal(null).safeParse(undefined).success).toBe(false)
+    expect(z.literal(undefined).safeParse(undefined).success).toBe(true)
+    expect(z.literal(undefined).safeParse(null).success).toBe(false)
+    expect(z.literal(0).safeParse(-0).success).toBe(true)
+    expect(z.literal(NaN).safeParse(NaN).success).toBe(false) // zodと同じく === で比較する
+  })
+
+  test("bigint / symbolのリテラル", () => {
+    // BigIntは値が等しければ一致する
+    expect(z.literal(2n ** 100n).safeParse(2n ** 100n).success).toBe(true)
+    expect(z.literal(1n).safeParse(1).success).toBe(false)
+    expect(z.literal(1n).value).toBe(1n)
+
+    // Symbolは同一のSymbolのみ一致する
+    const sym = Symbol("a")
+    expect(z.literal(sym).parse(sym)).toBe(sym)
+    expect(z.literal(sym).safeParse(Symbol("a")).success).toBe(false)
+    expect(z.literal(Symbol.for("b")).safeParse(Symbol.for("b")).success).toBe(true)
+  })
+})
@@ -0,0 +1,92 @@
+import { create_zod } from "@zod-rs/wasm"
+import { afterEach, describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.config locale", () => {
+  afterEach(() => {
+    // 言語を英語に戻す
+    z.config({ locale: "en" })
+  })
+
+  test("z.config", () => {
+    // デフォルトは英語
+    expect(z.config()).toEqual({ locale: "en" })
+    expect(z.config({ locale: "ja" })).toEqual({ locale: "ja" })
+    expect(z.config()).toEqual({ locale: "ja" })
+
+    // サポートしていない言語はエラー
+    expect(() => { z.config({ locale: "xx" }) }).toThrow("Unsupported locale: xx")
+    expect(z.config()).toEqual({ locale: "ja" })
+  })
+
+  test("英語（zodと同じメッセージ）", () => {
+    const message = (schema, value) => schema.safeParse(value).error.issues[0].message
+    expect(message(z.string(), 1)).toBe("Expected string, received number")
+    expect(message(z.string(), undefined)).toBe("Required")
+    expect(message(z.string().min(3), "a")).toBe("String must contain at least 3 character(s)")
+    expect(message(z.string().max(1), "ab")).toBe("String must contain at most 1 character(s)")
+    expect(message(z.string().length(2), "a")).toBe("String must contain exactly 2 character(s)")
+    expect(message(z.string().email(), "a")).toBe("Invalid email")
+    expect(message(z.string().regex(/a/), "b")).toBe("Invalid")
+    expect(message(z.string().includes("a"), "b")).toBe("Invalid input: must include \"a\"")
+    expect(message(z.string().startsWith("a"), "b")).toBe("Invalid input: must start with \"a\"")
+    expect(message(z.string().endsWith("a"), "b")).toBe("Invalid input: must end with \"a\"")
+    expect(message(z.number().gt(5), 5)).toBe("Number must be greater than 5")
+    expect(message(z.number().gte(5), 4)).toBe("Number must be greater than or equal to 5")
+    expect(message(z.number().lt(5), 5)).toBe("Number must be less than 5")
+    expect(message(z.number().int(), 1.5)).toBe("Expected integer, received float")
+    expect(message(z.number().multipleOf(3), 4)).toBe("Number must be a multiple of 3")
+    // biome-ignore lint/style/useNumberNamespace: <explanation>
+    expect(message(z.number().finite(), Infinity)).toBe("Number must be finite")
+    expect(message(z.bigint().lte(5n), 6n)).toBe("BigInt must be less than or equal to 5")
+    expect(message(z.array(z.string()).min(2), [])).toBe("Array must contain at least 2 element(s)")
+    expect(message(z.object({}).strict(), { a: 1, b: 2 })).toBe("Unrecognized key(s) in object: 'a', 'b'")
+    expect(message(z.object({ a: z.string() }).keyof(), "b")).toBe("Invalid enum value. Expected 'a', received 'b'")
+  })
+
+  test("日本語", () => {
+    z.config({ locale: "ja" })
+    const message = (schema, value) => schema.safeParse(value).error.issues[0].message
+    expect(message(z.string(), 1)).toBe("string型が必要ですが、number型が入力されました")
+    expect(message(z.string(), undefined)).toBe("必須項目です")
+    expect(message(z.string().min(3), "a")).toBe("3文字以上である必要があります")
+    expect(message(z.string().max(1), "ab")).toBe("1文字以下である必要があります")
+    expect(message(z.number().gt(5), 5)).toBe("5より大きい必要があります")
+    expect(message(z.number().lte(5), 6)).toBe("5以下である必要があります")
+    expect(message(z.array(z.string()).length(2), [])).toBe("配列の要素数はちょうど2個である必要があります")
+    expect(message(z.string().startsWith("a"), "b")).toBe("\"a\"で始まる必要があります")
+    // positionは0始まりのUTF-16のインデックスとして表示する
+    expect(message(z.string().includes("a", { position: 0 }), "b")).toBe("位置0以降に\"a\"を含む必要があります")
+    expect(message(z.date(), new Date("invalid"))).toBe("無効な日付です")
+    expect(message(z.date().min(new Date(1000)), new Date(0))).toBe(`${new Date(1000)}以降の日時である必要があります`)
+    expect(message(z.union([z.string(), z.number()]), true)).toBe("入力形式が間違っています")
+
+    // カスタムメッセージやerrorMapは言語設定より優先される
+    expect(message(z.string().min(3, "custom"), "a")).toBe("custom")
+    expect(z.string().safeParse(1, { errorMap: (issue, ctx) => ({ message: `[${ctx.defaultError}]` }) }).error.issues[0].message)
+      .toBe("[string型が必要ですが、number型が入力されました]")
+  })
+
+  test("その他の言語", () => {
+    const message = (schema, value) => schema.safeParse(value).error.issues[0].message
+
+    z.config({ locale: "de" })
+    expect(message(z.string(), undefined)).toBe("Erforderlich")
+    expect(message(z.string().min(3), "a")).toBe("String muss mindestens 3 Zeichen enthalten")
+
+    z.config({ locale: "fr" })
+    expect(message(z.string(), undefined)).toBe("Requis")
+    expect(message(z.number().gt(5), 5)).toBe("Le nombre doit être supérieur à 5")
+
+    z.config({ locale: "zh" })
+    expect(message(z.string(), undefined)).toBe("必填")
+    expect(message(z.array(z.string()).max(1), ["a", "b"])).toBe("数组必须包含至多 1 个元素")
+  })
+
+  test("z.defaultErrorMapも言語設定に従う", () => {
+    z.config({ locale: "ja" })
+    const issue = { code: "invalid_type", expected: "string", received: "undefined", path: [] }
+    expect(z.defaultErrorMap(issue, { defaultError: "", data: undefined }).message).toBe("必須項目です")
+  })
+})
@@ -0,0 +1,55 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.map", () => {
+  test("z.map", () => {
+    expect(z.map).toBeDefined()
+    const schema = z.map(z.string(), z.number())
+    const result = schema.parse(new Map([["a", 1], ["b", 2]]))
+    expect(result instanceof Map).toBe(true)
+    expect(Array.from(result.entries())).toEqual([["a", 1], ["b", 2]])
+    expect(schema.parse(new Map()).size).toBe(0)
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(new Map([["a", "1"]])) }).toThrow()
+    expect(() => { schema.parse(new Map([[1, 1]])) }).toThrow()
+    expect(() => { schema.parse({ a: 1 }) }).toThrow()
+    expect(() => { schema.parse(new Set()) }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodMap")
+  })
+
+  test("keySchema / valueSchema", () => {
+    const key = z.string()
+    const value = z.number()
+    const schema = z.map(key, value)
+    expect(schema.keySchema).toBe(key)
+    expect(schema.valueSchema).toBe(value)
+    expect(schema._def.keyType).toBe(key)
+    expect(schema._def.valueType).toBe(value)
+  })
+
+  test("パース結果の値を使用する", () => {
+    const schema = z.map(z.coerce.number(), z.object({ name: z.string() }))
+    const result = schema.parse(new Map([["1", { name: "a", extra: true }]]))
+    expect(Array.from(result.entries())).toEqual([[1, { name: "a" }]])
+  })
+
+  test("issue", () => {
+    const schema = z.map(z.string(), z.number().max(5))
+
+    // pathには [エントリーのインデックス, "key" | "value"] が含まれる
+    const issues = schema.safeParse(new Map([["a", 1], [2, 10]])).error.issues
+    expect(issues.map((issue) => [issue.code, issue.path])).toEqual([["invalid_type", [1, "key"]], ["too_big", [1, "value"]]])
+
+    expect(schema.safeParse({}).error.issues).toEqual([
+      { code: "invalid_type", expected: "map", received: "object", path: [], message: "Expected map, received object" },
+    ])
+    expect(z.object({}).safeParse(new Map()).error.issues[0].received).toBe("map")
+
+    const nested = z.object({ scores: schema }).safeParse({ scores: new Map([["a", "1"]]) }).error.issues[0]
+    expect(nested.path).toEqual(["scores", 0, "value"])
+  })
+})
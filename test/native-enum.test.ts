@@ -0,0 +1,56 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+// TypeScriptの enum Fruits { Apple, Banana, Cantaloupe = "cantaloupe" } のコンパイル結果と同じオブジェクト
+const Fruits = { Apple: 0, Banana: 1, Cantaloupe: "cantaloupe", 0: "Apple", 1: "Banana" }
+
+describe("test z.nativeEnum", () => {
+  test("数値と文字列のenum", () => {
+    expect(z.nativeEnum).toBeDefined()
+    const schema = z.nativeEnum(Fruits)
+    expect(schema.parse(Fruits.Apple)).toBe(0)
+    expect(schema.parse(1)).toBe(1)
+    expect(schema.parse("cantaloupe")).toBe("cantaloupe")
+    expect(schema._def.typeName).toBe("ZodNativeEnum")
+
+    // 逆引きのキー（"Apple" など）は値として許可しない
+    expect(() => { schema.parse("Apple") }).toThrow()
+    expect(() => { schema.parse("Cantaloupe") }).toThrow()
+    expect(() => { schema.parse(3) }).toThrow()
+    expect(() => { schema.parse("0") }).toThrow()
+  })
+
+  test("as constのオブジェクト", () => {
+    const schema = z.nativeEnum({ Apple: "apple", Banana: "banana", Cantaloupe: 3 })
+    expect(schema.parse("apple")).toBe("apple")
+    expect(schema.parse(3)).toBe(3)
+    expect(schema.safeParse("Apple").success).toBe(false)
+  })
+
+  test("issue", () => {
+    const schema = z.nativeEnum(Fruits)
+    expect(schema.safeParse(3).error.issues).toEqual([
+      {
+        code: "invalid_enum_value",
+        received: 3,
+        options: [0, 1, "cantaloupe"],
+        path: [],
+        message: "Invalid enum value. Expected 0 | 1 | 'cantaloupe', received '3'",
+      },
+    ])
+    // 文字列と数値以外はinvalid_type
+    expect(schema.safeParse(true).error.issues[0]).toMatchObject({
+      code: "invalid_type", expected: "0 | 1 | 'cantaloupe'", received: "boolean",
+    })
+    expect(schema.safeParse(NaN).error.issues[0].received).toBe("nan")
+    expect(z.nativeEnum(Fruits, { message: "custom" }).safeParse(3).error.issues[0].message).toBe("custom")
+  })
+
+  test("enum", () => {
+    expect(z.nativeEnum(Fruits).enum).toBe(Fruits)
+    expect(z.nativeEnum(Fruits).enum.Banana).toBe(1)
+    expect(z.nativeEnum(Fruits)._def.values).toBe(Fruits)
+  })
+})
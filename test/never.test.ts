@@ -0,0 +1,27 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.never", () => {
+  test("z.never", () => {
+    expect(z.never).toBeDefined()
+    const schema = z.never()
+
+    // どのような値もエラーになるべき
+    expect(() => { schema.parse(undefined) }).toThrow()
+    expect(() => { schema.parse(null) }).toThrow()
+    expect(() => { schema.parse(1) }).toThrow()
+
+    expect(schema.safeParse(1).error.issues[0]).toMatchObject({
+      code: "invalid_type", expected: "never", received: "number", message: "Expected never, received number",
+    })
+    expect(schema.safeParse(undefined).error.issues[0].message).toBe("Required")
+
+    // catchallに指定すると未知のキーを拒否できる
+    const obj = z.object({ a: z.string() }).catchall(z.never())
+    expect(obj.safeParse({ a: "a" }).success).toBe(true)
+    expect(obj.safeParse({ a: "a", b: 1 }).error.issues[0].path).toEqual(["b"])
+    expect(z.never()._def.typeName).toBe("ZodNever")
+  })
+})
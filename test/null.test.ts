@@ -0,0 +1,22 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.null", () => {
+  test("z.null", () => {
+    expect(z.null).toBeDefined()
+    const schema = z.null()
+    expect(schema.parse(null)).toBeNull()
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(undefined) }).toThrow() // undefinedはエラー
+    expect(() => { schema.parse(0) }).toThrow()
+    expect(() => { schema.parse({}) }).toThrow()
+
+    expect(schema.safeParse(0).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "null", received: "number" })
+    expect(schema.safeParse(undefined).error.issues[0].message).toBe("Required")
+    expect(z.null({ required_error: "null is required" }).safeParse(undefined).error.issues[0].message).toBe("null is required")
+    expect(z.null()._def.typeName).toBe("ZodNull")
+  })
+})
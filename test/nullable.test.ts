@@ -0,0 +1,47 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.nullable", () => {
+  test("z.nullable", () => {
+    expect(z.nullable).toBeDefined()
+    const schema = z.nullable(z.string())
+    expect(schema.parse("a")).toBe("a")
+    expect(schema.parse(null)).toBeNull()
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(undefined) }).toThrow() // undefinedはエラー
+    expect(() => { schema.parse(1) }).toThrow()
+
+    expect(schema.safeParse(undefined).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "string", received: "undefined", message: "Required" })
+    expect(schema._def.typeName).toBe("ZodNullable")
+  })
+
+  test(".nullable", () => {
+    const schema = z.number().int().nullable()
+    expect(schema.parse(null)).toBeNull()
+    expect(schema.parse(1)).toBe(1)
+    expect(schema.safeParse(1.5).error.issues[0].expected).toBe("integer")
+
+    // すべての型で使用できる
+    expect(z.boolean().nullable().parse(null)).toBeNull()
+    expect(z.array(z.string()).nullable().parse(null)).toBeNull()
+    expect(z.object({}).nullable().parse(null)).toBeNull()
+  })
+
+  test("unwrap", () => {
+    const inner = z.string()
+    const schema = z.nullable(inner)
+    expect(schema.unwrap()).toBe(inner)
+    expect(schema._def.innerType).toBe(inner)
+    expect(z.number().max(5).nullable().unwrap().maxValue).toBe(5)
+  })
+
+  test("deepPartial", () => {
+    const schema = z.object({ user: z.object({ id: z.number() }).nullable() }).deepPartial()
+    expect(schema.parse({ user: null })).toEqual({ user: null })
+    expect(schema.parse({ user: {} })).toEqual({ user: {} })
+    expect(schema.shape.user.unwrap()._def.typeName).toBe("ZodNullable")
+  })
+})
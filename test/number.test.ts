@@ -258,4 +258,45 @@ describe("test z.number", () => {
     // biome-ignore lint/style/useNumberNamespace: <explanation>
     expect(() => { schema.parse(Infinity) }).toThrow() // 無限大は有限ではない
   })
+
+  test("カスタムエラーメッセージ", () => {
+    // 文字列または { message } でメッセージを指定できる
+    expect(z.number().gt(5, "gt").safeParse(5).error.issues[0].message).toBe("gt")
+    expect(z.number().lte(5, { message: "lte" }).safeParse(6).error.issues[0].message).toBe("lte")
+    expect(z.number().int("int").safeParse(1.5).error.issues[0].message).toBe("int")
+    expect(z.number().positive("positive").safeParse(0).error.issues[0].message).toBe("positive")
+    expect(z.number().multipleOf(2, "multipleOf").safeParse(3).error.issues[0].message).toBe("multipleOf")
+    // biome-ignore lint/style/useNumberNamespace: <explanation>
+    expect(z.number().finite("finite").safeParse(Infinity).error.issues[0].message).toBe("finite")
+    expect(z.number().safe("safe").safeParse(2 ** 53).error.issues[0].message).toBe("safe")
+
+    // メッセージを指定しない場合はデフォルトのメッセージ
+    expect(z.number().gt(5).safeParse(5).error.issues[0].message).toBe("Number must be greater than 5")
+  })
+
+  test("複数の制約を順番に検証する", () => {
+    // gtとgteを重ねても上書きされず、両方が検証される
+    const schema = z.number().gt(0).gte(5, "gte5")
+    const issues = schema.safeParse(-1).error.issues
+    expect(issues.map((issue) => issue.code)).toEqual(["too_small", "too_small"])
+    expect(issues.map((issue) => issue.inclusive)).toEqual([false, true])
+    expect(issues[1].message).toBe("gte5")
+    expect(schema.safeParse(3).error.issues.length).toBe(1)
+
+    // positive().min(10)では両方の下限が残り、minValueは最も厳しい値になる
+    expect(z.number().positive().min(10).minValue).toBe(10)
+    expect(z.number().min(10).positive().minValue).toBe(10)
+    expect(z.number().lt(5).max(10).maxValue).toBe(5)
+
+    // _def.checksには追加された順に制約が並ぶ
+    expect(z.number().int().positive().multipleOf(2, "even")._def.checks).toEqual([
+      { kind: "int" },
+      { kind: "min", value: 0, inclusive: false },
+      { kind: "multipleOf", value: 2, message: "even" },
+    ])
+
+    // 浮動小数点の倍数も誤差なく判定する
+    expect(z.number().multipleOf(0.1).safeParse(0.3).success).toBe(true)
+    expect(z.number().multipleOf(0.01).safeParse(1.005).success).toBe(false)
+  })
 })
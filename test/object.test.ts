@@ -0,0 +1,180 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.object", () => {
+  test("z.object", () => {
+    expect(z.object).toBeDefined()
+    expect(() => { z.object({}) }).not.toThrow()
+    const schema = z.object({ name: z.string(), age: z.number() })
+    expect(schema).toBeDefined()
+
+    expect(() => { schema.parse({ name: "Alice", age: 30 }) }).not.toThrow()
+    expect(schema.parse({ name: "Alice", age: 30 })).toEqual({ name: "Alice", age: 30 })
+
+    // 各キーの検証に失敗した場合はエラー
+    expect(() => { schema.parse({ name: "Alice", age: "30" }) }).toThrow()
+    expect(() => { schema.parse({ name: 1, age: 30 }) }).toThrow()
+    expect(() => { schema.parse({ name: "Alice" }) }).toThrow() // キーが足りない
+
+    // オブジェクト以外はエラーになるべき
+    expect(() => { schema.parse(null) }).toThrow()
+    expect(() => { schema.parse(undefined) }).toThrow()
+    expect(() => { schema.parse("Alice") }).toThrow()
+    expect(() => { schema.parse([]) }).toThrow() // 配列はエラー
+
+    expect(schema.safeParse({ name: "Alice", age: 30 }).success).toBe(true)
+    expect(schema.safeParse({ name: "Alice", age: "30" }).success).toBe(false)
+  })
+
+  test("子スキーマの制約", () => {
+    const schema = z.object({ name: z.string().min(3), age: z.number().int().nonnegative() })
+    expect(() => { schema.parse({ name: "Bob", age: 0 }) }).not.toThrow()
+    expect(() => { schema.parse({ name: "Bo", age: 0 }) }).toThrow()
+    expect(() => { schema.parse({ name: "Bob", age: 1.5 }) }).toThrow()
+    expect(() => { schema.parse({ name: "Bob", age: -1 }) }).toThrow()
+  })
+
+  test("ネストしたオブジェクト", () => {
+    const schema = z.object({ user: z.object({ id: z.number() }) })
+    expect(schema.parse({ user: { id: 1, extra: true } })).toEqual({ user: { id: 1 } })
+    expect(() => { schema.parse({ user: { id: "1" } }) }).toThrow()
+    expect(() => { schema.parse({ user: null }) }).toThrow()
+  })
+
+  test("z.object().strip", () => {
+    // デフォルトでは未知のキーは取り除かれる
+    const schema = z.object({ name: z.string() })
+    expect(schema.parse({ name: "Alice", extra: 1 })).toEqual({ name: "Alice" })
+    expect(schema.strip().parse({ name: "Alice", extra: 1 })).toEqual({ name: "Alice" })
+    expect(schema.strict().strip().parse({ name: "Alice", extra: 1 })).toEqual({ name: "Alice" })
+  })
+
+  test("z.object().strict", () => {
+    expect(z.object({}).strict).toBeDefined()
+    const schema = z.object({ name: z.string() }).strict()
+    expect(() => { schema.parse({ name: "Alice" }) }).not.toThrow()
+    expect(() => { schema.parse({ name: "Alice", extra: 1 }) }).toThrow() // 未知のキーはエラー
+
+    expect(schema.safeParse({ name: "Alice" }).success).toBe(true)
+    expect(schema.safeParse({ name: "Alice", extra: 1 }).success).toBe(false)
+    expect(schema.safeParse({ name: "Alice", a: 1, b: 2 }).error.issues[0].message)
+      .toBe("Unrecognized key(s) in object: 'a', 'b'")
+  })
+
+  test("z.object().passthrough", () => {
+    expect(z.object({}).passthrough).toBeDefined()
+    const schema = z.object({ name: z.string() }).passthrough()
+    expect(schema.parse({ name: "Alice", extra: 1 })).toEqual({ name: "Alice", extra: 1 })
+    expect(() => { schema.parse({ name: 1, extra: 1 }) }).toThrow()
+  })
+
+  test("z.object().catchall", () => {
+    expect(z.object({}).catchall).toBeDefined()
+    const schema = z.object({ name: z.string() }).catchall(z.number())
+    expect(schema.parse({ name: "Alice", a: 1, b: 2 })).toEqual({ name: "Alice", a: 1, b: 2 })
+    expect(() => { schema.parse({ name: "Alice", a: "1" }) }).toThrow() // 未知のキーもスキーマで検証
+
+    // catchallはstrictより優先される
+    const strictSchema = schema.strict()
+    expect(() => { strictSchema.parse({ name: "Alice", a: 1 }) }).not.toThrow()
+  })
+
+  test("undefinedのキー", () => {
+    const schema = z.object({ name: z.string() }).passthrough()
+    expect(Object.keys(schema.parse({ name: "Alice", extra: undefined }))).toEqual(["name", "extra"])
+  })
+
+  test("z.object().shape", () => {
+    const nameSchema = z.string()
+    const schema = z.object({ name: nameSchema, age: z.number() })
+    expect(Object.keys(schema.shape)).toEqual(["name", "age"])
+    expect(schema.shape.name).toBe(nameSchema)
+    expect(schema._def.typeName).toBe("ZodObject")
+  })
+
+  test("z.object().keyof", () => {
+    const schema = z.object({ name: z.string(), age: z.number() }).keyof()
+    expect(schema._def.typeName).toBe("ZodEnum")
+    expect(schema.options).toEqual(["name", "age"])
+    expect(() => { schema.parse("name") }).not.toThrow()
+    expect(() => { schema.parse("age") }).not.toThrow()
+    expect(() => { schema.parse("email") }).toThrow()
+    expect(() => { schema.parse(1) }).toThrow()
+  })
+
+  test("z.object().extend", () => {
+    const base = z.object({ name: z.string() })
+    const schema = base.extend({ age: z.number(), name: z.number() }) // 同じキーは上書き
+    expect(Object.keys(schema.shape)).toEqual(["name", "age"])
+    expect(() => { schema.parse({ name: 1, age: 30 }) }).not.toThrow()
+    expect(() => { schema.parse({ name: "Alice", age: 30 }) }).toThrow()
+    // 元のスキーマは変更されない
+    expect(Object.keys(base.shape)).toEqual(["name"])
+  })
+
+  test("z.object().merge", () => {
+    const a = z.object({ name: z.string() })
+    const b = z.object({ age: z.number() }).strict()
+    const schema = a.merge(b)
+    expect(Object.keys(schema.shape)).toEqual(["name", "age"])
+    expect(() => { schema.parse({ name: "Alice", age: 30 }) }).not.toThrow()
+    // 未知のキーの扱いは結合される側の設定が使われる
+    expect(() => { schema.parse({ name: "Alice", age: 30, extra: 1 }) }).toThrow()
+    expect(schema._def.unknownKeys).toBe("strict")
+  })
+
+  test("z.object().pick / omit", () => {
+    const schema = z.object({ id: z.number(), name: z.string(), age: z.number() })
+    const picked = schema.pick({ id: true, name: true, unknown: true })
+    expect(Object.keys(picked.shape)).toEqual(["id", "name"])
+    expect(picked.parse({ id: 1, name: "Alice", age: 30 })).toEqual({ id: 1, name: "Alice" })
+
+    const omitted = schema.omit({ age: true })
+    expect(Object.keys(omitted.shape)).toEqual(["id", "name"])
+    expect(() => { omitted.parse({ id: 1, name: "Alice" }) }).not.toThrow()
+  })
+
+  test("z.object().partial", () => {
+    const schema = z.object({ name: z.string(), age: z.number() })
+    const partial = schema.partial()
+    expect(partial.shape.name._def.typeName).toBe("ZodOptional")
+    expect(partial.parse({})).toEqual({})
+    expect(partial.parse({ name: "Alice" })).toEqual({ name: "Alice" })
+    expect(() => { partial.parse({ name: 1 }) }).toThrow()
+
+    // maskで指定したキーのみoptionalにする
+    const partialName = schema.partial({ name: true })
+    expect(() => { partialName.parse({ age: 30 }) }).not.toThrow()
+    expect(() => { partialName.parse({ name: "Alice" }) }).toThrow()
+  })
+
+  test("z.object().deepPartial", () => {
+    const schema = z.object({ user: z.object({ id: z.number(), name: z.string() }) }).deepPartial()
+    expect(() => { schema.parse({}) }).not.toThrow()
+    expect(() => { schema.parse({ user: {} }) }).not.toThrow()
+    expect(() => { schema.parse({ user: { id: 1 } }) }).not.toThrow()
+    expect(() => { schema.parse({ user: { id: "1" } }) }).toThrow()
+    expect(schema.shape.user.unwrap().shape.id._def.typeName).toBe("ZodOptional")
+
+    // 配列スキーマの制約やエラーメッセージの設定は保持される
+    const withArray = z.object({ a: z.array(z.string(), { invalid_type_error: "X" }).min(2, "Y") }).deepPartial()
+    expect(withArray.safeParse({ a: "a" }).error.issues[0].message).toBe("X")
+    expect(withArray.safeParse({ a: ["a"] }).error.issues[0].message).toBe("Y")
+    expect(withArray.parse({ a: ["a", "b"] })).toEqual({ a: ["a", "b"] })
+  })
+
+  test("z.object().required", () => {
+    const schema = z.object({ name: z.string(), age: z.number() }).partial()
+    const required = schema.required()
+    expect(required.shape.name._def.typeName).toBe("ZodString")
+    expect(() => { required.parse({ name: "Alice", age: 30 }) }).not.toThrow()
+    expect(() => { required.parse({ name: "Alice" }) }).toThrow()
+
+    // maskで指定したキーのみ必須にする
+    const requiredName = schema.required({ name: true })
+    expect(() => { requiredName.parse({ name: "Alice" }) }).not.toThrow()
+    expect(() => { requiredName.parse({ age: 30 }) }).toThrow()
+  })
+})
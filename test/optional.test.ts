@@ -0,0 +1,67 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.optional", () => {
+  test("z.optional", () => {
+    expect(z.optional).toBeDefined()
+    const schema = z.optional(z.string())
+    expect(schema.parse("a")).toBe("a")
+    expect(schema.parse(undefined)).toBeUndefined()
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(null) }).toThrow() // nullはエラー
+    expect(() => { schema.parse(1) }).toThrow()
+
+    // issueは内側のスキーマのもの
+    expect(schema.safeParse(1).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "string", received: "number" })
+    expect(schema._def.typeName).toBe("ZodOptional")
+  })
+
+  test(".optional", () => {
+    const schema = z.string().min(3).optional()
+    expect(schema.parse(undefined)).toBeUndefined()
+    expect(schema.parse("abc")).toBe("abc")
+    expect(schema.safeParse("a").error.issues[0].code).toBe("too_small")
+
+    // すべての型で使用できる
+    expect(z.number().optional().parse(undefined)).toBeUndefined()
+    expect(z.array(z.string()).optional().parse(undefined)).toBeUndefined()
+    expect(z.object({ a: z.string() }).optional().parse(undefined)).toBeUndefined()
+    expect(z.enum(["a", "b"]).optional().parse(undefined)).toBeUndefined()
+    expect(z.literal("a").optional().parse(undefined)).toBeUndefined()
+  })
+
+  test("unwrap", () => {
+    const inner = z.string()
+    const schema = z.optional(inner)
+    expect(schema.unwrap()).toBe(inner)
+    expect(schema._def.innerType).toBe(inner)
+    expect(schema.optional().unwrap().unwrap()).toBe(inner)
+    expect(z.string().min(3).optional().unwrap().minLength).toBe(3)
+  })
+
+  test("オブジェクトのプロパティ", () => {
+    const schema = z.object({ name: z.string(), nickname: z.string().optional() })
+    expect(schema.parse({ name: "Alice" })).toEqual({ name: "Alice" })
+    expect(schema.parse({ name: "Alice", nickname: "A" })).toEqual({ name: "Alice", nickname: "A" })
+    expect(() => { schema.parse({ name: "Alice", nickname: 1 }) }).toThrow()
+    expect(schema.required().shape.nickname._def.typeName).toBe("ZodString")
+  })
+})
+
+describe("test z.nullish", () => {
+  test(".nullish", () => {
+    const schema = z.string().nullish()
+    expect(schema.parse("a")).toBe("a")
+    expect(schema.parse(undefined)).toBeUndefined()
+    expect(schema.parse(null)).toBeNull()
+    expect(() => { schema.parse(1) }).toThrow()
+
+    // nullable().optional()と同じ
+    expect(schema._def.typeName).toBe("ZodOptional")
+    expect(schema.unwrap()._def.typeName).toBe("ZodNullable")
+    expect(schema.unwrap().unwrap()._def.typeName).toBe("ZodString")
+  })
+})
@@ -0,0 +1,89 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.record", () => {
+  test("z.record(valueType)", () => {
+    expect(z.record).toBeDefined()
+    const schema = z.record(z.number())
+    expect(schema.parse({ a: 1, b: 2 })).toEqual({ a: 1, b: 2 })
+    expect(schema.parse({})).toEqual({})
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse({ a: "1" }) }).toThrow()
+    expect(() => { schema.parse(null) }).toThrow()
+    expect(() => { schema.parse([]) }).toThrow()
+    expect(() => { schema.parse("a") }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodRecord")
+    // キーのスキーマはz.string()になる
+    expect(schema.keySchema._def.typeName).toBe("ZodString")
+  })
+
+  test("z.record(keyType, valueType)", () => {
+    const schema = z.record(z.string().min(2), z.object({ name: z.string() }))
+    expect(schema.parse({ ab: { name: "a" } })).toEqual({ ab: { name: "a" } })
+    expect(() => { schema.parse({ a: { name: "a" } }) }).toThrow()
+    expect(() => { schema.parse({ ab: { name: 1 } }) }).toThrow()
+
+    // 値のスキーマで未知のキーが取り除かれる
+    expect(schema.parse({ ab: { name: "a", extra: 1 } })).toEqual({ ab: { name: "a" } })
+  })
+
+  test("keySchema / valueSchema", () => {
+    const key = z.string()
+    const value = z.number()
+    const schema = z.record(key, value)
+    expect(schema.keySchema).toBe(key)
+    expect(schema.valueSchema).toBe(value)
+    expect(schema.element).toBe(value)
+    expect(schema._def.keyType).toBe(key)
+    expect(schema._def.valueType).toBe(value)
+  })
+
+  test("enum / literalのキー", () => {
+    const schema = z.record(z.enum(["id", "name"]), z.string())
+    expect(schema.parse({ id: "1", name: "Alice" })).toEqual({ id: "1", name: "Alice" })
+    // zod 3.24と同じく、すべてのキーが揃っている必要はない
+    expect(schema.parse({ id: "1" })).toEqual({ id: "1" })
+    expect(schema.parse({})).toEqual({})
+
+    // enumに含まれないキーはエラー
+    expect(schema.safeParse({ id: "1", age: "20" }).error.issues).toEqual([
+      {
+        code: "invalid_enum_value",
+        received: "age",
+        options: ["id", "name"],
+        path: ["age"],
+        message: "Invalid enum value. Expected 'id' | 'name', received 'age'",
+      },
+    ])
+
+    const literal = z.record(z.literal("a").or(z.literal("b")), z.number())
+    expect(literal.parse({ a: 1, b: 2 })).toEqual({ a: 1, b: 2 })
+    expect(literal.safeParse({ c: 1 }).error.issues[0]).toMatchObject({ code: "invalid_union", path: ["c"] })
+  })
+
+  test("issue", () => {
+    const schema = z.record(z.string().max(1), z.number())
+
+    // キーと値のissueのpathにはキーが含まれる
+    const issues = schema.safeParse({ a: "1", bb: 2 }).error.issues
+    expect(issues.map((issue) => [issue.code, issue.path])).toEqual([["invalid_type", ["a"]], ["too_big", ["bb"]]])
+
+    expect(z.record(z.string()).safeParse(1).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "object", received: "number" })
+    expect(z.record(z.string(), { invalid_type_error: "must be a record" }).safeParse(1).error.issues[0].message).toBe("must be a record")
+    expect(z.record(z.string(), z.string(), { invalid_type_error: "must be a record" }).safeParse(1).error.issues[0].message).toBe("must be a record")
+
+    // ネストした位置のpathが保持される
+    const nested = z.object({ flags: z.record(z.boolean()) }).safeParse({ flags: { beta: "yes" } }).error.issues[0]
+    expect(nested.path).toEqual(["flags", "beta"])
+  })
+
+  test("キーの変換", () => {
+    const schema = z.record(z.coerce.number().int(), z.string())
+    expect(schema.parse({ 1: "a", 2: "b" })).toEqual({ 1: "a", 2: "b" })
+    expect(schema.safeParse({ a: "a" }).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "number", received: "nan", path: ["a"] })
+  })
+})
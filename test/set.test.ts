@@ -0,0 +1,69 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.set", () => {
+  test("z.set", () => {
+    expect(z.set).toBeDefined()
+    const schema = z.set(z.number())
+    const result = schema.parse(new Set([1, 2, 3]))
+    expect(result instanceof Set).toBe(true)
+    expect(Array.from(result)).toEqual([1, 2, 3])
+    expect(schema.parse(new Set()).size).toBe(0)
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(new Set(["1"])) }).toThrow()
+    expect(() => { schema.parse([1, 2]) }).toThrow()
+    expect(() => { schema.parse(new Map()) }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodSet")
+    expect(schema._def.valueType._def.typeName).toBe("ZodNumber")
+  })
+
+  test("min / max / size / nonempty", () => {
+    const set = (...values) => new Set(values)
+
+    const min = z.set(z.string()).min(2)
+    expect(() => { min.parse(set("a", "b")) }).not.toThrow()
+    expect(() => { min.parse(set("a")) }).toThrow()
+
+    const max = z.set(z.string()).max(2)
+    expect(() => { max.parse(set("a", "b")) }).not.toThrow()
+    expect(() => { max.parse(set("a", "b", "c")) }).toThrow()
+
+    const size = z.set(z.string()).size(2)
+    expect(() => { size.parse(set("a", "b")) }).not.toThrow()
+    expect(() => { size.parse(set("a")) }).toThrow()
+    expect(() => { size.parse(set("a", "b", "c")) }).toThrow()
+
+    const nonempty = z.set(z.string()).nonempty()
+    expect(() => { nonempty.parse(set("a")) }).not.toThrow()
+    expect(() => { nonempty.parse(set()) }).toThrow()
+
+    expect(z.set(z.string()).min(1, "too few")._def.minSize).toEqual({ value: 1, message: "too few" })
+    expect(z.set(z.string()).max(3)._def.maxSize).toEqual({ value: 3 })
+    expect(z.set(z.string())._def.minSize).toBeNull()
+
+    // zodと同じく、負の値や小数もそのまま数値として比較する
+    expect(() => { z.set(z.string()).min(-1).parse(set()) }).not.toThrow()
+    expect(() => { z.set(z.string()).min(1.5).parse(set("a")) }).toThrow()
+    expect(() => { z.set(z.string()).max(-1).parse(set()) }).toThrow()
+    expect(() => { z.set(z.string()).size(1.5).parse(set("a")) }).toThrow()
+    expect(z.set(z.string()).max(2.5).safeParse(set("a", "b", "c")).error.issues[0].maximum).toBe(2.5)
+  })
+
+  test("issue", () => {
+    const schema = z.set(z.number()).min(3, { message: "at least 3" }).max(4)
+    expect(schema.safeParse(new Set([1])).error.issues).toEqual([
+      { code: "too_small", minimum: 3, type: "set", inclusive: true, exact: false, path: [], message: "at least 3" },
+    ])
+    expect(schema.safeParse(new Set([1, 2, 3, 4, 5])).error.issues[0]).toMatchObject({ code: "too_big", maximum: 4, type: "set" })
+
+    // 要素のissueのpathにはインデックスが含まれる
+    expect(z.set(z.number()).safeParse(new Set([1, "a", 2, "b"])).error.issues.map((issue) => issue.path)).toEqual([[1], [3]])
+
+    expect(z.set(z.number()).safeParse([1]).error.issues[0]).toMatchObject({ code: "invalid_type", expected: "set", received: "array" })
+    expect(z.string().safeParse(new Set()).error.issues[0].received).toBe("set")
+  })
+})
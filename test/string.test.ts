@@ -162,4 +162,113 @@ describe("test z.string", () => {
     expect(schema.safeParse("http://example.com").success).toBe(false)
     expect(schema.safeParse("invalid-url").success).toBe(false)
   })
+
+  test("カスタムエラーメッセージ", () => {
+    // 文字列または { message } でメッセージを指定できる
+    expect(z.string().min(5, "too short").safeParse("a").error.issues[0].message).toBe("too short")
+    expect(z.string().max(1, { message: "too long" }).safeParse("ab").error.issues[0].message).toBe("too long")
+    expect(z.string().length(2, "length").safeParse("a").error.issues[0].message).toBe("length")
+    expect(z.string().email("email").safeParse("a").error.issues[0].message).toBe("email")
+    expect(z.string().url({ message: "url" }).safeParse("a").error.issues[0].message).toBe("url")
+    expect(z.string().regex(/^a$/, "regex").safeParse("b").error.issues[0].message).toBe("regex")
+    expect(z.string().includes("a", { message: "includes" }).safeParse("b").error.issues[0].message).toBe("includes")
+    expect(z.string().startsWith("a", "startsWith").safeParse("b").error.issues[0].message).toBe("startsWith")
+    expect(z.string().endsWith("a", "endsWith").safeParse("b").error.issues[0].message).toBe("endsWith")
+    expect(z.string().nonempty("nonempty").safeParse("").error.issues[0].message).toBe("nonempty")
+
+    // メッセージを指定しない場合はデフォルトのメッセージ
+    expect(z.string().min(5).safeParse("a").error.issues[0].message).toBe("String must contain at least 5 character(s)")
+  })
+
+  test("複数の制約を順番に検証する", () => {
+    // 同じ種類の制約を重ねても上書きされず、追加された順にすべて検証される
+    const schema = z.string().min(2).min(4, "min4").max(10).startsWith("a")
+    const issues = schema.safeParse("b").error.issues
+    expect(issues.map((issue) => issue.code)).toEqual(["too_small", "too_small", "invalid_string"])
+    expect(issues[1].message).toBe("min4")
+    expect(schema.safeParse("bcd").error.issues.map((issue) => issue.minimum)).toEqual([4, undefined])
+
+    // _def.checksには { kind, value, message } の形式で制約が並ぶ
+    expect(schema._def.checks).toEqual([
+      { kind: "min", value: 2 },
+      { kind: "min", value: 4, message: "min4" },
+      { kind: "max", value: 10 },
+      { kind: "startsWith", value: "a" },
+    ])
+    expect(z.string().regex(/a/).email()._def.checks).toEqual([{ kind: "regex", regex: /a/ }, { kind: "email" }])
+
+    // ゲッターは最も厳しい値を返す
+    expect(schema.minLength).toBe(4)
+    expect(schema.maxLength).toBe(10)
+    expect(z.string().minLength).toBeNull()
+    expect(z.string().email().isEmail).toBe(true)
+    expect(z.string().isURL).toBe(false)
+
+    // zodと同じく、負の値や小数もそのまま数値として比較する
+    expect(z.string().min(-1).safeParse("").success).toBe(true)
+    expect(z.string().min(1.5).safeParse("a").success).toBe(false)
+    expect(z.string().max(-1).safeParse("").success).toBe(false)
+    expect(z.string().max(1.5).safeParse("a").success).toBe(true)
+    expect(z.string().length(1.5).safeParse("a").success).toBe(false)
+    expect(z.string().min(-1)._def.checks).toEqual([{ kind: "min", value: -1 }])
+    expect(z.string().min(1.5).safeParse("a").error.issues[0].minimum).toBe(1.5)
+    expect(z.string().min(0.5).min(1.5).minLength).toBe(1.5)
+  })
+
+  test("文字数はJavaScriptと同じUTF-16コード単位で数える", () => {
+    // マルチバイト文字もJavaScriptの length と同じく1文字として数える
+    expect("日本語テスト".length).toBe(6)
+    expect(z.string().max(6).safeParse("日本語テスト").success).toBe(true)
+    expect(z.string().length(6).safeParse("日本語テスト").success).toBe(true)
+    expect(z.string().min(7).safeParse("日本語テスト").success).toBe(false)
+
+    // サロゲートペアの絵文字は2文字として数える
+    expect(z.string().length(2).safeParse("😀").success).toBe(true)
+    expect(z.string().max(1).safeParse("😀").success).toBe(false)
+  })
+
+  test("includesのpositionはその位置以降を検索する", () => {
+    const schema = z.string().includes("includes", { position: 2 })
+    expect(schema.safeParse("XXXincludesXX").success).toBe(true)
+    expect(schema.safeParse("XXincludesXX").success).toBe(true)
+    expect(schema.safeParse("XincludesXX").success).toBe(false)
+
+    // positionはUTF-16のインデックスとして扱う
+    expect(z.string().includes("a", { position: 2 }).safeParse("日本a").success).toBe(true)
+    expect(z.string().includes("a", { position: 3 }).safeParse("日本a").success).toBe(false)
+
+    // 数値以外のpositionもJavaScriptと同じく数値に変換する
+    expect(z.string().includes("a", { position: "2" }).safeParse("aXa").success).toBe(true)
+    expect(z.string().includes("a", { position: "2" }).safeParse("aaX").success).toBe(false)
+    expect(z.string().includes("a", { position: "x" }).safeParse("aXX").success).toBe(true) // NaNは0
+    expect(z.string().includes("a", { position: NaN }).safeParse("aXX").success).toBe(true)
+    expect(z.string().includes("a", { position: -5 }).safeParse("aXX").success).toBe(true) // 負の値は0
+    expect(z.string().includes("a", { position: 1.9 }).safeParse("XaX").success).toBe(true) // 小数点以下は切り捨て
+    expect(z.string().includes("", { position: 100 }).safeParse("abc").success).toBe(true) // 文字列の長さに収める
+    expect(z.string().includes("a", { position: Infinity }).safeParse("abc").success).toBe(false)
+
+    // _defやissueのpositionは指定された値をそのまま保持する
+    const stringPosition = z.string().includes("a", { position: "2" })
+    expect(stringPosition._def.checks[0]).toEqual({ kind: "includes", value: "a", position: "2" })
+    const issue = stringPosition.safeParse("aaX").error.issues[0]
+    expect(issue.validation).toEqual({ includes: "a", position: "2" })
+    // メッセージにpositionを含めるのは数値の場合のみ
+    expect(issue.message).toBe("Invalid input: must include \"a\"")
+    expect(z.string().includes("a", { position: Infinity }).safeParse("abc").error.issues[0].message)
+      .toBe("Invalid input: must include \"a\" at one or more positions greater than or equal to Infinity")
+  })
+
+  test("graphemesで書記素クラスタ単位で数える", () => {
+    // 結合文字や異体字セレクタ、ZWJシーケンスも見た目上の1文字として数える
+    const schema = z.string().graphemes().max(2)
+    expect(schema.safeParse("👨‍👩‍👧‍👦👍🏽").success).toBe(true)
+    expect(schema.safeParse("がぎぐ").success).toBe(false)
+    expect(z.string().graphemes().length(1).safeParse("🇯🇵").success).toBe(true)
+    expect(z.string().length(1).safeParse("🇯🇵").success).toBe(false)
+
+    // 制約の前後どちらで指定しても同じ
+    expect(z.string().min(2).graphemes().safeParse("😀").success).toBe(false)
+    expect(z.string().graphemes()._def.graphemes).toBe(true)
+    expect(z.string()._def.graphemes).toBe(false)
+  })
 })
@@ -0,0 +1,24 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.symbol", () => {
+  test("z.symbol", () => {
+    expect(z.symbol).toBeDefined()
+    const schema = z.symbol()
+    const sym = Symbol("a")
+    expect(schema.parse(sym)).toBe(sym)
+    expect(() => { schema.parse(Symbol.iterator) }).not.toThrow()
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse("a") }).toThrow() // 文字列はエラー
+    expect(() => { schema.parse(undefined) }).toThrow() // undefinedはエラー
+    expect(() => { schema.parse({}) }).toThrow() // オブジェクトはエラー
+
+    expect(schema.safeParse("a").error.issues[0]).toMatchObject({ code: "invalid_type", expected: "symbol", received: "string" })
+    // Symbolは "symbol" 型として扱う
+    expect(z.string().safeParse(sym).error.issues[0].received).toBe("symbol")
+    expect(z.symbol()._def.typeName).toBe("ZodSymbol")
+  })
+})
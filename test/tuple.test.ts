@@ -0,0 +1,72 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.tuple", () => {
+  test("z.tuple", () => {
+    expect(z.tuple).toBeDefined()
+    const schema = z.tuple([z.string(), z.number()])
+    expect(schema.parse(["a", 1])).toEqual(["a", 1])
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse([1, "a"]) }).toThrow() // 位置が異なる
+    expect(() => { schema.parse(["a"]) }).toThrow() // 要素数が足りない
+    expect(() => { schema.parse(["a", 1, 2]) }).toThrow() // 要素数が多すぎる
+    expect(() => { schema.parse({ 0: "a", 1: 1 }) }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodTuple")
+    expect(z.tuple([]).parse([])).toEqual([])
+  })
+
+  test("items", () => {
+    const string = z.string()
+    const schema = z.tuple([string, z.number()])
+    expect(schema.items.length).toBe(2)
+    expect(schema.items[0]).toBe(string)
+    expect(schema._def.items[1]._def.typeName).toBe("ZodNumber")
+    expect(schema._def.rest).toBeNull()
+  })
+
+  test("rest", () => {
+    const schema = z.tuple([z.string()]).rest(z.number())
+    expect(schema.parse(["a"])).toEqual(["a"])
+    expect(schema.parse(["a", 1, 2, 3])).toEqual(["a", 1, 2, 3])
+    expect(() => { schema.parse(["a", 1, "b"]) }).toThrow()
+    expect(() => { schema.parse([]) }).toThrow()
+    expect(schema._def.rest._def.typeName).toBe("ZodNumber")
+    expect(schema.items.length).toBe(1)
+
+    // 元のスキーマは変更されない
+    const base = z.tuple([z.string()])
+    base.rest(z.number())
+    expect(() => { base.parse(["a", 1]) }).toThrow()
+  })
+
+  test("issue", () => {
+    const schema = z.tuple([z.string(), z.number()])
+
+    // 各要素のissueのpathにはインデックスが含まれる
+    expect(schema.safeParse([1, "a"]).error.issues.map((issue) => issue.path)).toEqual([[0], [1]])
+    expect(z.tuple([z.string()]).rest(z.number()).safeParse(["a", 1, "b"]).error.issues[0].path).toEqual([2])
+
+    expect(schema.safeParse(["a"]).error.issues).toEqual([
+      { code: "too_small", minimum: 2, type: "array", inclusive: true, exact: false, path: [], message: "Array must contain at least 2 element(s)" },
+    ])
+    expect(schema.safeParse(["a", 1, 2]).error.issues).toEqual([
+      { code: "too_big", maximum: 2, type: "array", inclusive: true, exact: false, path: [], message: "Array must contain at most 2 element(s)" },
+    ])
+    // 要素数が多すぎる場合も、要素の検証は行う
+    expect(schema.safeParse([1, 1, 2]).error.issues.map((issue) => issue.code)).toEqual(["too_big", "invalid_type"])
+
+    expect(schema.safeParse("a").error.issues[0]).toMatchObject({ code: "invalid_type", expected: "array", received: "string" })
+  })
+
+  test("deepPartial", () => {
+    const schema = z.object({ pair: z.tuple([z.object({ a: z.string() }), z.number()]) }).deepPartial()
+    expect(schema.parse({ pair: [{}, 1] })).toEqual({ pair: [{}, 1] })
+    // 要素自体はoptionalにならない
+    expect(() => { schema.parse({ pair: [{ a: "a" }, undefined] }) }).toThrow()
+    expect(() => { schema.parse({ pair: [{ a: 1 }, 1] }) }).toThrow()
+  })
+})
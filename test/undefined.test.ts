@@ -0,0 +1,22 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.undefined", () => {
+  test("z.undefined", () => {
+    expect(z.undefined).toBeDefined()
+    const schema = z.undefined()
+    expect(schema.parse(undefined)).toBeUndefined()
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(null) }).toThrow() // nullはエラー
+    expect(() => { schema.parse(0) }).toThrow()
+    expect(() => { schema.parse("") }).toThrow()
+
+    expect(schema.safeParse(null).error.issues[0]).toEqual({
+      code: "invalid_type", expected: "undefined", received: "null", path: [], message: "Expected undefined, received null",
+    })
+    expect(z.undefined()._def.typeName).toBe("ZodUndefined")
+  })
+})
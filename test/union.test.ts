@@ -0,0 +1,95 @@
+import { create_zod, ZodError } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.union", () => {
+  test("z.union", () => {
+    expect(z.union).toBeDefined()
+    const schema = z.union([z.string(), z.number()])
+    expect(schema.parse("a")).toBe("a")
+    expect(schema.parse(1)).toBe(1)
+
+    // 以下は全てエラーになるべき
+    expect(() => { schema.parse(true) }).toThrow()
+    expect(() => { schema.parse(null) }).toThrow()
+    expect(() => { schema.parse(undefined) }).toThrow()
+
+    expect(schema._def.typeName).toBe("ZodUnion")
+    expect(schema.options.length).toBe(2)
+    expect(schema._def.options[1]._def.typeName).toBe("ZodNumber")
+  })
+
+  test(".or", () => {
+    const schema = z.string().or(z.number())
+    expect(schema._def.typeName).toBe("ZodUnion")
+    expect(schema.parse("a")).toBe("a")
+    expect(schema.parse(1)).toBe(1)
+    expect(() => { schema.parse(true) }).toThrow()
+
+    // 連鎖させることもできる
+    const chained = z.string().or(z.number()).or(z.boolean())
+    expect(chained.parse(true)).toBe(true)
+    expect(() => { chained.parse(null) }).toThrow()
+  })
+
+  test("最初に成功した選択肢の結果を返す", () => {
+    const schema = z.union([z.object({ a: z.string() }), z.object({ a: z.string(), b: z.number() })])
+    // 1つ目の選択肢で未知のキーが取り除かれる
+    expect(schema.parse({ a: "a", b: 1 })).toEqual({ a: "a" })
+
+    const literals = z.union([z.literal("a"), z.literal("b"), z.literal("c")])
+    expect(literals.parse("c")).toBe("c")
+    expect(() => { literals.parse("d") }).toThrow()
+  })
+
+  test("invalid_union", () => {
+    const schema = z.union([z.string(), z.number()])
+    const issues = schema.safeParse(true).error.issues
+    expect(issues.length).toBe(1)
+    expect(issues[0].code).toBe("invalid_union")
+    expect(issues[0].path).toEqual([])
+    expect(issues[0].message).toBe("Invalid input")
+
+    // 選択肢ごとのエラーがunionErrorsに含まれる
+    const unionErrors = issues[0].unionErrors
+    expect(unionErrors.length).toBe(2)
+    expect(unionErrors[0] instanceof ZodError).toBe(true)
+    expect(unionErrors[0].issues).toEqual([
+      { code: "invalid_type", expected: "string", received: "boolean", path: [], message: "Expected string, received boolean" },
+    ])
+    expect(unionErrors[1].issues[0].expected).toBe("number")
+
+    // ネストした位置のpathが保持される
+    const nested = z.object({ value: z.union([z.string(), z.number()]) }).safeParse({ value: true }).error.issues[0]
+    expect(nested.path).toEqual(["value"])
+    expect(nested.unionErrors[0].issues[0].path).toEqual(["value"])
+  })
+
+  test("dirtyの選択肢", () => {
+    // 型は一致したが制約を満たさなかった選択肢がある場合は、そのissueを返す
+    const schema = z.union([z.string().min(3), z.number()])
+    const issues = schema.safeParse("a").error.issues
+    expect(issues.length).toBe(1)
+    expect(issues[0]).toMatchObject({ code: "too_small", minimum: 3 })
+
+    // 成功する選択肢があればdirtyの選択肢より優先する
+    expect(z.union([z.string().min(3), z.string()]).parse("a")).toBe("a")
+  })
+
+  test("errorMap / message", () => {
+    const schema = z.union([z.string(), z.number()], { errorMap: () => ({ message: "string or number" }) })
+    expect(schema.safeParse(true).error.issues[0].message).toBe("string or number")
+
+    const custom = z.string().or(z.number())
+    expect(custom.safeParse(true, { errorMap: (issue, ctx) => ({ message: issue.code === "invalid_union" ? "union" : ctx.defaultError }) }).error.issues[0].message).toBe("union")
+  })
+
+  test("format / flatten", () => {
+    const schema = z.object({ value: z.union([z.string(), z.number()]) })
+    const error = schema.safeParse({ value: true }).error
+    expect(error.format().value._errors).toEqual(["Expected string, received boolean", "Expected number, received boolean"])
+    expect(error.flatten().fieldErrors.value).toEqual(["Invalid input"])
+    expect(JSON.parse(error.message)[0].unionErrors[0].issues[0].code).toBe("invalid_type")
+  })
+})
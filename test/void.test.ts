@@ -0,0 +1,21 @@
+import { create_zod } from "@zod-rs/wasm"
+import { describe, expect, test } from "vitest"
+
+const z = create_zod()
+
+describe("test z.void", () => {
+  test("z.void", () => {
+    expect(z.void).toBeDefined()
+    const schema = z.void()
+    expect(schema.parse(undefined)).toBeUndefined()
+
+    // undefined以外はエラー
+    expect(() => { schema.parse(null) }).toThrow()
+    expect(() => { schema.parse(0) }).toThrow()
+
+    expect(schema.safeParse(null).error.issues[0]).toMatchObject({
+      code: "invalid_type", expected: "void", received: "null", message: "Expected void, received null",
+    })
+    expect(z.void()._def.typeName).toBe("ZodVoid")
+  })
+})